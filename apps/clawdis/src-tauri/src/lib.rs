@@ -1,9 +1,12 @@
 use std::sync::Mutex;
+use std::time::{Duration, Instant};
 use tauri::{
-    menu::{Menu, MenuBuilder, MenuItem},
+    menu::{Menu, MenuBuilder, MenuItem, SubmenuBuilder},
     tray::{MouseButton, MouseButtonState, TrayIcon, TrayIconBuilder, TrayIconEvent},
     AppHandle, Emitter, Manager, State,
 };
+use tauri_plugin_notification::NotificationExt;
+use tauri_plugin_store::StoreExt;
 
 /// Connection status for the tray
 #[derive(Debug, Clone, serde::Deserialize)]
@@ -16,7 +19,7 @@ pub enum ConnectionStatus {
 }
 
 /// Health status for the tray
-#[derive(Debug, Clone, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum HealthStatus {
     Healthy,
@@ -41,6 +44,9 @@ pub struct TrayState {
     usage: Mutex<TokenUsage>,
     health: Mutex<HealthStatus>,
     latency: Mutex<Option<u64>>,
+    icon_color: Mutex<Option<IconColor>>,
+    menu_items: Mutex<Option<TrayMenuItems>>,
+    recent_chats: Mutex<Vec<ChatSummary>>,
 }
 
 impl Default for TrayState {
@@ -51,8 +57,80 @@ impl Default for TrayState {
             usage: Mutex::new(TokenUsage::default()),
             health: Mutex::new(HealthStatus::Unknown),
             latency: Mutex::new(None),
+            icon_color: Mutex::new(None),
+            menu_items: Mutex::new(None),
+            recent_chats: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+/// A recently-active chat, surfaced in the tray's "Recent Chats" submenu
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatSummary {
+    pub id: String,
+    pub title: String,
+    pub last_active: String,
+}
+
+const MAX_RECENT_CHATS: usize = 8;
+
+/// Handles to the menu items whose text changes on every status/usage/health update, so
+/// `rebuild_tray_menu` can mutate them in place instead of reconstructing the whole menu
+struct TrayMenuItems {
+    status: MenuItem<tauri::Wry>,
+    health: MenuItem<tauri::Wry>,
+    usage: MenuItem<tauri::Wry>,
+}
+
+/// RGB color used to tint the tray glyph, keyed by connection/health state
+type IconColor = (u8, u8, u8);
+
+const ICON_SIZE: u32 = 32;
+const ICON_COLOR_CONNECTED: IconColor = (52, 199, 89); // green
+const ICON_COLOR_DEGRADED: IconColor = (255, 159, 10); // amber
+const ICON_COLOR_ERROR: IconColor = (255, 59, 48); // red
+const ICON_COLOR_DISCONNECTED: IconColor = (142, 142, 147); // grey
+
+/// Pick the tray glyph color for the current connection/health pairing; the
+/// worse of the two states wins so the icon never looks healthier than it is
+fn tray_icon_color(status: &ConnectionStatus, health: &HealthStatus) -> IconColor {
+    match (status, health) {
+        (ConnectionStatus::Error, _) | (_, HealthStatus::Unhealthy) => ICON_COLOR_ERROR,
+        (ConnectionStatus::Connecting, _) | (_, HealthStatus::Degraded) => ICON_COLOR_DEGRADED,
+        (ConnectionStatus::Connected, _) => ICON_COLOR_CONNECTED,
+        (ConnectionStatus::Disconnected, _) => ICON_COLOR_DISCONNECTED,
+    }
+}
+
+/// Recolor a filled-circle monochrome mask with `color`, producing a tray-ready image
+fn tinted_tray_icon(color: IconColor) -> tauri::image::Image<'static> {
+    let center = ICON_SIZE as f32 / 2.0 - 0.5;
+    let radius = ICON_SIZE as f32 / 2.0 - 1.0;
+    let mut rgba = Vec::with_capacity((ICON_SIZE * ICON_SIZE * 4) as usize);
+    for y in 0..ICON_SIZE {
+        for x in 0..ICON_SIZE {
+            let dx = x as f32 - center;
+            let dy = y as f32 - center;
+            let alpha: u8 = if (dx * dx + dy * dy).sqrt() <= radius { 255 } else { 0 };
+            rgba.extend_from_slice(&[color.0, color.1, color.2, alpha]);
         }
     }
+    tauri::image::Image::new_owned(rgba, ICON_SIZE, ICON_SIZE)
+}
+
+/// Apply the status-appropriate tray icon, skipping the update if the color hasn't changed
+fn apply_tray_icon(state: &TrayState, status: &ConnectionStatus, health: &HealthStatus) -> Result<(), String> {
+    let color = tray_icon_color(status, health);
+    let mut icon_color = state.icon_color.lock().unwrap();
+    if *icon_color == Some(color) {
+        return Ok(());
+    }
+    if let Some(tray) = state.tray.lock().unwrap().as_ref() {
+        tray.set_icon(Some(tinted_tray_icon(color))).map_err(|e| e.to_string())?;
+    }
+    *icon_color = Some(color);
+    Ok(())
 }
 
 /// Format token count with k/M suffixes
@@ -66,21 +144,65 @@ fn format_tokens(count: u64) -> String {
     }
 }
 
-/// Build the tray menu with current connection status, usage, and health
-fn build_tray_menu(
-    app: &AppHandle,
-    status: &ConnectionStatus,
-    usage: &TokenUsage,
-    health: &HealthStatus,
-    latency: Option<u64>,
-) -> Result<Menu<tauri::Wry>, Box<dyn std::error::Error>> {
+const TOKEN_BUDGET_KEY: &str = "tokenBudget";
+const DEFAULT_TOKEN_BUDGET: u64 = 100_000;
+const TOKEN_SOFT_BUDGET_RATIO_KEY: &str = "tokenSoftBudgetRatio";
+const DEFAULT_SOFT_BUDGET_RATIO: f64 = 0.8;
+
+/// Read the configured token budget (per-session or daily, caller's choice), falling back to a default
+fn read_token_budget(app: &AppHandle) -> u64 {
+    read_store_u64(app, TOKEN_BUDGET_KEY, DEFAULT_TOKEN_BUDGET)
+}
+
+/// Read the configured soft-budget ratio (e.g. 0.8 for "warn at 80%"), falling back to a default
+fn read_soft_budget_ratio(app: &AppHandle) -> f64 {
+    app.store(SETTINGS_STORE)
+        .ok()
+        .and_then(|store| store.get(TOKEN_SOFT_BUDGET_RATIO_KEY))
+        .and_then(|value| value.as_f64())
+        .unwrap_or(DEFAULT_SOFT_BUDGET_RATIO)
+}
+
+/// Which of the given `(threshold, label)` budget lines `new_total` has newly crossed past
+/// `previous_total`. Kept pure (no store/notification access) so the crossing semantics can be
+/// unit tested without a running app.
+fn crossed_budget_thresholds(previous_total: u64, new_total: u64, thresholds: &[(u64, String)]) -> Vec<String> {
+    thresholds
+        .iter()
+        .filter(|(threshold, _)| previous_total < *threshold && new_total >= *threshold)
+        .map(|(_, label)| label.clone())
+        .collect()
+}
+
+/// Fire a notification the first time `new_total` crosses a budget threshold that
+/// `previous_total` had not, so the user is warned once per crossing rather than on every update
+fn notify_budget_crossings(app: &AppHandle, previous_total: u64, new_total: u64) {
+    let hard_budget = read_token_budget(app);
+    let soft_ratio = read_soft_budget_ratio(app);
+    let soft_budget = (hard_budget as f64 * soft_ratio) as u64;
+    let soft_pct = (soft_ratio * 100.0).round() as u64;
+
+    let thresholds = [(soft_budget, format!("{}%", soft_pct)), (hard_budget, "100%".to_string())];
+    for label in crossed_budget_thresholds(previous_total, new_total, &thresholds) {
+        let _ = app
+            .notification()
+            .builder()
+            .title("Clawdis")
+            .body(format!("Token usage crossed {} of budget ({} tokens)", label, format_tokens(new_total)))
+            .show();
+    }
+}
+
+/// Compute the text for the three dynamic menu lines (status, health, usage)
+fn dynamic_item_texts(app: &AppHandle, status: &ConnectionStatus, usage: &TokenUsage, health: &HealthStatus, latency: Option<u64>) -> (String, String, String) {
     // Status indicator text
     let status_text = match status {
         ConnectionStatus::Connected => "● Connected",
         ConnectionStatus::Connecting => "○ Connecting...",
         ConnectionStatus::Disconnected => "○ Disconnected",
         ConnectionStatus::Error => "✕ Connection Error",
-    };
+    }
+    .to_string();
 
     // Health indicator text
     let health_text = match health {
@@ -97,7 +219,7 @@ fn build_tray_menu(
     };
 
     // Usage text (only show if there's usage)
-    let usage_text = if usage.total_tokens > 0 {
+    let mut usage_text = if usage.total_tokens > 0 {
         format!(
             "Tokens: {} (↓{} ↑{})",
             format_tokens(usage.total_tokens),
@@ -107,12 +229,46 @@ fn build_tray_menu(
     } else {
         "Tokens: —".to_string()
     };
+    if usage.total_tokens >= read_token_budget(app) {
+        usage_text.push_str(" ⚠ over budget");
+    }
+
+    (status_text, health_text, usage_text)
+}
+
+/// Build the "Recent Chats" submenu: one item per chat (id `chat:<id>`), then a
+/// separator and a "Clear Recent" item
+fn build_recent_chats_submenu(
+    app: &AppHandle,
+    recent_chats: &[ChatSummary],
+) -> Result<tauri::menu::Submenu<tauri::Wry>, Box<dyn std::error::Error>> {
+    let mut builder = SubmenuBuilder::new(app, "Recent Chats");
+    for chat in recent_chats {
+        let item = MenuItem::with_id(app, format!("chat:{}", chat.id), &chat.title, true, None::<&str>)?;
+        builder = builder.item(&item);
+    }
+    let clear_recent = MenuItem::with_id(app, "clear_recent", "Clear Recent", true, None::<&str>)?;
+    builder = builder.separator().item(&clear_recent);
+    Ok(builder.build()?)
+}
+
+/// Build the tray menu from scratch, returning it along with handles to the dynamic items
+fn build_tray_menu(
+    app: &AppHandle,
+    status: &ConnectionStatus,
+    usage: &TokenUsage,
+    health: &HealthStatus,
+    latency: Option<u64>,
+) -> Result<(Menu<tauri::Wry>, TrayMenuItems), Box<dyn std::error::Error>> {
+    let (status_text, health_text, usage_text) = dynamic_item_texts(app, status, usage, health, latency);
 
     // Create menu items
-    let status_item = MenuItem::with_id(app, "status", status_text, false, None::<&str>)?;
+    let status_item = MenuItem::with_id(app, "status", &status_text, false, None::<&str>)?;
     let health_item = MenuItem::with_id(app, "health", &health_text, false, None::<&str>)?;
     let usage_item = MenuItem::with_id(app, "usage", &usage_text, false, None::<&str>)?;
     let new_chat = MenuItem::with_id(app, "new_chat", "New Chat", true, None::<&str>)?;
+    let recent_chats = app.state::<TrayState>().recent_chats.lock().unwrap().clone();
+    let recent_chats_submenu = build_recent_chats_submenu(app, &recent_chats)?;
     let show = MenuItem::with_id(app, "show", "Show Clawdis", true, None::<&str>)?;
     let settings = MenuItem::with_id(app, "settings", "Settings...", true, Some("CmdOrCtrl+,"))?;
     let quit = MenuItem::with_id(app, "quit", "Quit Clawdis", true, Some("CmdOrCtrl+Q"))?;
@@ -124,6 +280,7 @@ fn build_tray_menu(
         .item(&usage_item)
         .separator()
         .item(&new_chat)
+        .item(&recent_chats_submenu)
         .separator()
         .item(&show)
         .item(&settings)
@@ -131,7 +288,13 @@ fn build_tray_menu(
         .item(&quit)
         .build()?;
 
-    Ok(menu)
+    let items = TrayMenuItems {
+        status: status_item,
+        health: health_item,
+        usage: usage_item,
+    };
+
+    Ok((menu, items))
 }
 
 /// Create the system tray with menu items
@@ -141,14 +304,15 @@ fn create_tray(app: &tauri::App) -> Result<(), Box<dyn std::error::Error>> {
     let usage = TokenUsage::default();
     let health = HealthStatus::Unknown;
     let latency = None;
-    let menu = build_tray_menu(&app_handle, &status, &usage, &health, latency)?;
+    let (menu, menu_items) = build_tray_menu(&app_handle, &status, &usage, &health, latency)?;
 
     // Tooltip based on status
     let tooltip = "Clawdis - Disconnected";
 
-    // Create the tray icon
+    // Create the tray icon, tinted for the initial status/health pairing
+    let initial_color = tray_icon_color(&status, &health);
     let tray = TrayIconBuilder::new()
-        .icon(app.default_window_icon().cloned().unwrap())
+        .icon(tinted_tray_icon(initial_color))
         .menu(&menu)
         .tooltip(tooltip)
         .show_menu_on_left_click(false)
@@ -174,6 +338,18 @@ fn create_tray(app: &tauri::App) -> Result<(), Box<dyn std::error::Error>> {
                 "quit" => {
                     app.exit(0);
                 }
+                "clear_recent" => {
+                    let state: State<TrayState> = app.state();
+                    state.recent_chats.lock().unwrap().clear();
+                    *state.menu_items.lock().unwrap() = None;
+                    let _ = rebuild_tray_menu(app, &state);
+                }
+                other if other.starts_with("chat:") => {
+                    let chat_id = other.trim_start_matches("chat:").to_string();
+                    if let Some(window) = app.get_webview_window("main") {
+                        let _ = window.emit("tray-open-chat", chat_id);
+                    }
+                }
                 _ => {}
             }
         })
@@ -197,6 +373,8 @@ fn create_tray(app: &tauri::App) -> Result<(), Box<dyn std::error::Error>> {
     let state: State<TrayState> = app.state();
     *state.tray.lock().unwrap() = Some(tray);
     *state.status.lock().unwrap() = status;
+    *state.icon_color.lock().unwrap() = Some(initial_color);
+    *state.menu_items.lock().unwrap() = Some(menu_items);
 
     Ok(())
 }
@@ -216,15 +394,33 @@ fn rebuild_tray_menu(app: &AppHandle, state: &TrayState) -> Result<(), String> {
         ConnectionStatus::Error => "Clawdis - Connection Error",
     };
 
-    // Rebuild menu
-    let menu = build_tray_menu(app, &status, &usage, &health, latency).map_err(|e| e.to_string())?;
-
-    // Update tray
+    // Update the tray tooltip unconditionally; it's cheap and has no menu-rebuild cost
     if let Some(tray) = state.tray.lock().unwrap().as_ref() {
         tray.set_tooltip(Some(tooltip)).map_err(|e| e.to_string())?;
-        tray.set_menu(Some(menu)).map_err(|e| e.to_string())?;
     }
 
+    let (status_text, health_text, usage_text) = dynamic_item_texts(app, &status, &usage, &health, latency);
+
+    // Fast path: menu structure is unchanged, so just mutate the three dynamic items in place
+    // instead of tearing down and rebuilding the whole menu (avoids flicker on macOS/Windows).
+    // Only fall back to a full rebuild the first time, or whenever something has cleared
+    // `menu_items` to signal that the menu's structure needs to be reconstructed.
+    let mut menu_items = state.menu_items.lock().unwrap();
+    if let Some(items) = menu_items.as_ref() {
+        items.status.set_text(&status_text).map_err(|e| e.to_string())?;
+        items.health.set_text(&health_text).map_err(|e| e.to_string())?;
+        items.usage.set_text(&usage_text).map_err(|e| e.to_string())?;
+    } else {
+        let (menu, built_items) = build_tray_menu(app, &status, &usage, &health, latency).map_err(|e| e.to_string())?;
+        if let Some(tray) = state.tray.lock().unwrap().as_ref() {
+            tray.set_menu(Some(menu)).map_err(|e| e.to_string())?;
+        }
+        *menu_items = Some(built_items);
+    }
+    drop(menu_items);
+
+    apply_tray_icon(state, &status, &health)?;
+
     Ok(())
 }
 
@@ -238,7 +434,12 @@ fn set_tray_status(app: AppHandle, state: State<TrayState>, status: ConnectionSt
 /// Update the tray token usage (called from frontend)
 #[tauri::command]
 fn set_tray_usage(app: AppHandle, state: State<TrayState>, usage: TokenUsage) -> Result<(), String> {
-    *state.usage.lock().unwrap() = usage;
+    let new_total = usage.total_tokens;
+    // Swap the whole `TokenUsage` under one lock acquisition so a racing call can't observe
+    // (or leave behind) a `previous_total`/displayed-usage pair from two different updates
+    let previous_total = std::mem::replace(&mut *state.usage.lock().unwrap(), usage).total_tokens;
+    notify_budget_crossings(&app, previous_total, new_total);
+
     rebuild_tray_menu(&app, &state)
 }
 
@@ -258,6 +459,111 @@ fn set_tray_health(app: AppHandle, state: State<TrayState>, health: HealthInfo)
     rebuild_tray_menu(&app, &state)
 }
 
+/// Sort chats newest-first by `last_active` and cap the list to `MAX_RECENT_CHATS`. Pure so
+/// the sort-then-truncate invariant can be unit tested directly.
+fn cap_recent_chats(mut chats: Vec<ChatSummary>) -> Vec<ChatSummary> {
+    chats.sort_by(|a, b| b.last_active.cmp(&a.last_active));
+    chats.truncate(MAX_RECENT_CHATS);
+    chats
+}
+
+/// Update the tray's "Recent Chats" submenu (called from frontend)
+#[tauri::command]
+fn set_tray_recent_chats(app: AppHandle, state: State<TrayState>, chats: Vec<ChatSummary>) -> Result<(), String> {
+    *state.recent_chats.lock().unwrap() = cap_recent_chats(chats);
+    // The submenu's entries were replaced, so force the next rebuild down the full path
+    *state.menu_items.lock().unwrap() = None;
+    rebuild_tray_menu(&app, &state)
+}
+
+const SETTINGS_STORE: &str = "settings.json";
+const HEALTH_POLL_INTERVAL_KEY: &str = "healthPollIntervalSecs";
+const DEFAULT_HEALTH_POLL_INTERVAL_SECS: u64 = 30;
+const MIN_HEALTH_POLL_INTERVAL_SECS: u64 = 1;
+const HEALTH_PROBE_URL_KEY: &str = "healthProbeUrl";
+const HEALTH_PROBE_TIMEOUT_SECS: u64 = 5;
+const DEGRADED_LATENCY_THRESHOLD_MS: u64 = 1500;
+
+/// Read a u64 setting from the store, falling back to `default` if the store or key is absent
+fn read_store_u64(app: &AppHandle, key: &str, default: u64) -> u64 {
+    app.store(SETTINGS_STORE)
+        .ok()
+        .and_then(|store| store.get(key))
+        .and_then(|value| value.as_u64())
+        .unwrap_or(default)
+}
+
+/// Read the configured backend URL to probe, if the user has set one
+fn read_health_probe_url(app: &AppHandle) -> Option<String> {
+    app.store(SETTINGS_STORE)
+        .ok()
+        .and_then(|store| store.get(HEALTH_PROBE_URL_KEY))
+        .and_then(|value| value.as_str().map(str::to_string))
+}
+
+/// Classify a probe's round-trip latency into a `HealthStatus`. Pure so the degraded-threshold
+/// boundary can be unit tested without a running app.
+fn classify_latency(latency_ms: u64) -> HealthStatus {
+    if latency_ms > DEGRADED_LATENCY_THRESHOLD_MS {
+        HealthStatus::Degraded
+    } else {
+        HealthStatus::Healthy
+    }
+}
+
+/// Ping the backend and turn round-trip latency into a `HealthStatus` reading
+async fn probe_health(client: &reqwest::Client, url: &str) -> (HealthStatus, Option<u64>) {
+    let start = Instant::now();
+    match client
+        .get(url)
+        .timeout(Duration::from_secs(HEALTH_PROBE_TIMEOUT_SECS))
+        .send()
+        .await
+    {
+        Ok(response) if response.status().is_success() => {
+            let latency_ms = start.elapsed().as_millis() as u64;
+            (classify_latency(latency_ms), Some(latency_ms))
+        }
+        _ => (HealthStatus::Unhealthy, None),
+    }
+}
+
+/// Spawn the background worker that keeps tray health fresh even when the webview is
+/// backgrounded or busy, rather than relying solely on frontend-pushed `set_tray_health` calls.
+/// The reading is written from this task, but applying it to the tray is queued onto the main
+/// thread so the event loop only wakes to redraw when a reading actually differs from the last.
+fn spawn_health_poller(app_handle: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let client = reqwest::Client::new();
+        loop {
+            let interval_secs =
+                read_store_u64(&app_handle, HEALTH_POLL_INTERVAL_KEY, DEFAULT_HEALTH_POLL_INTERVAL_SECS).max(MIN_HEALTH_POLL_INTERVAL_SECS);
+
+            if let Some(url) = read_health_probe_url(&app_handle) {
+                let (health, latency) = probe_health(&client, &url).await;
+                let changed = {
+                    let state: State<TrayState> = app_handle.state();
+                    let mut health_guard = state.health.lock().unwrap();
+                    let mut latency_guard = state.latency.lock().unwrap();
+                    let changed = *health_guard != health || *latency_guard != latency;
+                    *health_guard = health;
+                    *latency_guard = latency;
+                    changed
+                };
+                if changed {
+                    let handle = app_handle.clone();
+                    let _ = app_handle.run_on_main_thread(move || {
+                        let state: State<TrayState> = handle.state();
+                        let _ = rebuild_tray_menu(&handle, &state);
+                    });
+                }
+            }
+
+            tokio::time::sleep(Duration::from_secs(interval_secs)).await;
+        }
+    });
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
@@ -269,11 +575,92 @@ pub fn run() {
         .plugin(tauri_plugin_updater::Builder::new().build())
         .plugin(tauri_plugin_process::init())
         .manage(TrayState::default())
-        .invoke_handler(tauri::generate_handler![set_tray_status, set_tray_usage, set_tray_health])
+        .invoke_handler(tauri::generate_handler![
+            set_tray_status,
+            set_tray_usage,
+            set_tray_health,
+            set_tray_recent_chats
+        ])
         .setup(|app| {
             create_tray(app)?;
+            spawn_health_poller(app.handle().clone());
             Ok(())
         })
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tray_icon_color_prefers_worse_of_status_and_health() {
+        assert_eq!(tray_icon_color(&ConnectionStatus::Connected, &HealthStatus::Healthy), ICON_COLOR_CONNECTED);
+        assert_eq!(tray_icon_color(&ConnectionStatus::Disconnected, &HealthStatus::Unknown), ICON_COLOR_DISCONNECTED);
+
+        // Health degradation should tint the icon even while "connected"
+        assert_eq!(tray_icon_color(&ConnectionStatus::Connected, &HealthStatus::Degraded), ICON_COLOR_DEGRADED);
+        assert_eq!(tray_icon_color(&ConnectionStatus::Connected, &HealthStatus::Unhealthy), ICON_COLOR_ERROR);
+
+        // A connection error always wins, regardless of the reported health
+        assert_eq!(tray_icon_color(&ConnectionStatus::Error, &HealthStatus::Healthy), ICON_COLOR_ERROR);
+    }
+
+    #[test]
+    fn crossed_budget_thresholds_fires_once_per_upward_crossing() {
+        let thresholds = [(80u64, "80%".to_string()), (100u64, "100%".to_string())];
+
+        // Crossing only the soft threshold
+        assert_eq!(crossed_budget_thresholds(70, 90, &thresholds), vec!["80%".to_string()]);
+
+        // Crossing both thresholds in one jump
+        assert_eq!(crossed_budget_thresholds(70, 120, &thresholds), vec!["80%".to_string(), "100%".to_string()]);
+
+        // Already over a threshold: no repeat notification on further increases
+        assert!(crossed_budget_thresholds(90, 95, &thresholds).is_empty());
+
+        // Usage dropping back down never "crosses" anything
+        assert!(crossed_budget_thresholds(120, 90, &thresholds).is_empty());
+    }
+
+    fn chat(id: &str, last_active: &str) -> ChatSummary {
+        ChatSummary {
+            id: id.to_string(),
+            title: id.to_string(),
+            last_active: last_active.to_string(),
+        }
+    }
+
+    #[test]
+    fn cap_recent_chats_keeps_newest_when_input_is_unsorted() {
+        let chats = vec![
+            chat("older", "2026-07-01T00:00:00Z"),
+            chat("newest", "2026-07-30T00:00:00Z"),
+            chat("middle", "2026-07-15T00:00:00Z"),
+        ];
+
+        let capped = cap_recent_chats(chats);
+
+        assert_eq!(capped, vec![chat("newest", "2026-07-30T00:00:00Z"), chat("middle", "2026-07-15T00:00:00Z"), chat("older", "2026-07-01T00:00:00Z")]);
+    }
+
+    #[test]
+    fn cap_recent_chats_drops_oldest_beyond_the_cap() {
+        let chats: Vec<ChatSummary> = (0..MAX_RECENT_CHATS + 3)
+            .map(|i| chat(&format!("chat-{i}"), &format!("2026-07-{:02}T00:00:00Z", i + 1)))
+            .collect();
+
+        let capped = cap_recent_chats(chats);
+
+        assert_eq!(capped.len(), MAX_RECENT_CHATS);
+        // The newest chats have the highest index, so they must be the ones retained
+        assert_eq!(capped[0].id, format!("chat-{}", MAX_RECENT_CHATS + 2));
+    }
+
+    #[test]
+    fn classify_latency_uses_the_degraded_threshold_as_an_exclusive_boundary() {
+        assert_eq!(classify_latency(DEGRADED_LATENCY_THRESHOLD_MS), HealthStatus::Healthy);
+        assert_eq!(classify_latency(DEGRADED_LATENCY_THRESHOLD_MS + 1), HealthStatus::Degraded);
+    }
+}